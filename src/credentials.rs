@@ -0,0 +1,214 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::error::ProfileKitError;
+use crate::model::UserProfile;
+
+/// Default PBKDF2 iteration count, chosen to take roughly 100ms on modern
+/// hardware as of this writing. Callers on newer hardware should raise it.
+pub const DEFAULT_PBKDF2_ITERATIONS: u32 = 600_000;
+
+const SALT_LEN: usize = 16;
+const HASH_LEN: usize = 32;
+
+/// Which key-derivation function produced a [`Credential`]'s hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KdfAlgorithm {
+    /// PBKDF2-HMAC-SHA256, with `iterations` as the cost parameter.
+    Pbkdf2HmacSha256,
+
+    /// Argon2id, with `iterations` as the `t_cost` parameter.
+    Argon2id,
+}
+
+/// Parameters used to derive a [`Credential`] from a plaintext password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordParams {
+    /// The KDF to use.
+    pub algorithm: KdfAlgorithm,
+
+    /// The cost parameter: PBKDF2 iteration count, or Argon2 `t_cost`.
+    pub iterations: u32,
+}
+
+impl Default for PasswordParams {
+    /// Defaults to PBKDF2-HMAC-SHA256 with [`DEFAULT_PBKDF2_ITERATIONS`]
+    /// iterations.
+    fn default() -> Self {
+        Self {
+            algorithm: KdfAlgorithm::Pbkdf2HmacSha256,
+            iterations: DEFAULT_PBKDF2_ITERATIONS,
+        }
+    }
+}
+
+/// A derived password hash, together with everything needed to reproduce
+/// it and verify a later attempt.
+///
+/// Storing the KDF algorithm and iteration count alongside the hash lets
+/// a server raise its cost factor over time: re-hash with the new
+/// parameters the next time a user successfully logs in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Credential {
+    /// The KDF algorithm used to derive `hash`.
+    pub algorithm: KdfAlgorithm,
+
+    /// The cost parameter used to derive `hash`.
+    pub iterations: u32,
+
+    /// Random per-credential salt.
+    pub salt: Vec<u8>,
+
+    /// The derived key.
+    pub hash: Vec<u8>,
+}
+
+impl Credential {
+    fn derive(plaintext: &str, salt: &[u8], params: PasswordParams) -> Vec<u8> {
+        let mut hash = vec![0u8; HASH_LEN];
+        match params.algorithm {
+            KdfAlgorithm::Pbkdf2HmacSha256 => {
+                pbkdf2_hmac::<Sha256>(plaintext.as_bytes(), salt, params.iterations, &mut hash);
+            }
+            KdfAlgorithm::Argon2id => {
+                let argon2_params = Params::new(
+                    Params::DEFAULT_M_COST,
+                    params.iterations,
+                    Params::DEFAULT_P_COST,
+                    Some(HASH_LEN),
+                )
+                .expect("argon2 params with a fixed-size output should be valid");
+                let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params);
+                argon2
+                    .hash_password_into(plaintext.as_bytes(), salt, &mut hash)
+                    .expect("argon2 hashing with a fixed-size output should not fail");
+            }
+        }
+        hash
+    }
+
+    /// Derives a new credential for `plaintext` using `params`, generating
+    /// a fresh random salt.
+    pub fn new(plaintext: &str, params: PasswordParams) -> Self {
+        let mut salt = vec![0u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let hash = Self::derive(plaintext, &salt, params);
+        Self {
+            algorithm: params.algorithm,
+            iterations: params.iterations,
+            salt,
+            hash,
+        }
+    }
+
+    /// Returns whether `plaintext`, re-derived with this credential's
+    /// stored algorithm, iterations and salt, matches the stored hash.
+    ///
+    /// Comparison is constant-time to avoid leaking hash contents through
+    /// timing.
+    pub fn verify(&self, plaintext: &str) -> bool {
+        let params = PasswordParams {
+            algorithm: self.algorithm,
+            iterations: self.iterations,
+        };
+        let candidate = Self::derive(plaintext, &self.salt, params);
+        constant_time_eq(&candidate, &self.hash)
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+impl UserProfile {
+    /// Derives and stores a password credential for this profile using
+    /// `params`, generating a fresh random salt.
+    pub fn set_password(&mut self, plaintext: &str, params: PasswordParams) {
+        self.credential = Some(Credential::new(plaintext, params));
+    }
+
+    /// Returns whether `plaintext` matches this profile's stored
+    /// credential. Returns `false` if no credential has been set.
+    pub fn verify_password(&self, plaintext: &str) -> bool {
+        match &self.credential {
+            Some(credential) => credential.verify(plaintext),
+            None => false,
+        }
+    }
+
+    /// Checks `plaintext` against this profile's stored credential.
+    ///
+    /// Returns `Err(ProfileKitError::AuthenticationFailed)` if it does
+    /// not match, or if no credential has been set.
+    pub fn check_password(&self, plaintext: &str) -> Result<(), ProfileKitError> {
+        if self.verify_password(plaintext) {
+            Ok(())
+        } else {
+            Err(ProfileKitError::AuthenticationFailed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pbkdf2_round_trip() {
+        let mut profile = UserProfile::new("u1".to_string(), "test@example.com".to_string());
+        let params = PasswordParams {
+            algorithm: KdfAlgorithm::Pbkdf2HmacSha256,
+            iterations: 10_000,
+        };
+        profile.set_password("hunter2", params);
+        assert!(profile.verify_password("hunter2"));
+        assert!(!profile.verify_password("wrong-password"));
+    }
+
+    #[test]
+    fn test_argon2id_round_trip() {
+        let mut profile = UserProfile::new("u2".to_string(), "test2@example.com".to_string());
+        let params = PasswordParams {
+            algorithm: KdfAlgorithm::Argon2id,
+            iterations: 2,
+        };
+        profile.set_password("hunter2", params);
+        assert!(profile.verify_password("hunter2"));
+        assert!(!profile.verify_password("wrong-password"));
+    }
+
+    #[test]
+    fn test_verify_without_credential() {
+        let profile = UserProfile::new("u3".to_string(), "test3@example.com".to_string());
+        assert!(!profile.verify_password("anything"));
+    }
+
+    #[test]
+    fn test_check_password_returns_authentication_failed() {
+        let mut profile = UserProfile::new("u4".to_string(), "test4@example.com".to_string());
+        profile.set_password("hunter2", PasswordParams::default());
+        assert!(profile.check_password("hunter2").is_ok());
+        assert!(matches!(
+            profile.check_password("wrong-password"),
+            Err(ProfileKitError::AuthenticationFailed)
+        ));
+    }
+
+    #[test]
+    fn test_distinct_salts_produce_distinct_hashes() {
+        let a = Credential::new("same-password", PasswordParams::default());
+        let b = Credential::new("same-password", PasswordParams::default());
+        assert_ne!(a.salt, b.salt);
+        assert_ne!(a.hash, b.hash);
+    }
+}
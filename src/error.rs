@@ -27,4 +27,8 @@ pub enum ProfileKitError {
     /// A generic storage-related error, usually from file systems, in-memory stores, or cloud buckets.
     #[error("Storage error occurred")]
     StorageError,
+
+    /// Returned when a credential check (e.g. password verification) fails.
+    #[error("Authentication failed")]
+    AuthenticationFailed,
 }
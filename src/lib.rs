@@ -0,0 +1,24 @@
+//! `profile_kit` is a small, storage-agnostic toolkit for modeling and
+//! persisting user profiles.
+//!
+//! It provides a plain-data [`model`] (profile, attributes, preferences),
+//! a [`repository`] abstraction so applications can swap storage backends
+//! without touching domain code, and an [`error`] type shared across both.
+
+/// Password credential storage and KDF-based hashing/verification.
+///
+/// Enabled by the `credentials` cargo feature.
+#[cfg(feature = "credentials")]
+pub mod credentials;
+
+pub mod error;
+pub mod model;
+pub mod repository;
+
+/// Handlebars-based rendering of profile lifecycle notifications.
+///
+/// Enabled by the `templates` cargo feature.
+#[cfg(feature = "templates")]
+pub mod templates;
+
+pub mod verification;
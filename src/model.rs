@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 
+#[cfg(feature = "credentials")]
+use crate::credentials::Credential;
+
 /// Represents a user profile containing identity, contact information,
 /// and optional attributes and preferences.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -18,6 +21,21 @@ pub struct UserProfile {
     /// Optional user preferences (e.g., language, newsletter settings).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preferences: Option<UserPreferences>,
+
+    /// The user's password credential, if one has been set.
+    ///
+    /// Never (de)serialized, so a stored password hash cannot leak through
+    /// an incidental `UserProfile` JSON round-trip.
+    ///
+    /// Present when the `credentials` cargo feature is enabled.
+    #[cfg(feature = "credentials")]
+    #[serde(skip)]
+    pub credential: Option<Credential>,
+
+    /// The user's privilege tier. Profiles persisted before this field
+    /// existed deserialize as [`Role::Normal`].
+    #[serde(default)]
+    pub role: Role,
 }
 
 impl UserProfile {
@@ -28,6 +46,9 @@ impl UserProfile {
             email: email.to_ascii_lowercase(),
             attributes: None,
             preferences: None,
+            #[cfg(feature = "credentials")]
+            credential: None,
+            role: Role::default(),
         }
     }
 
@@ -70,6 +91,66 @@ impl UserProfile {
     pub fn get_preferences(&self) -> Option<&UserPreferences> {
         self.preferences.as_ref()
     }
+
+    /// Sets the user's role.
+    pub fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
+    /// Returns the user's role.
+    pub fn get_role(&self) -> Role {
+        self.role
+    }
+}
+
+/// A user's privilege tier.
+///
+/// Defaults to [`Role::Normal`] so existing stored profiles without a
+/// `role` field (serialized before this enum was added) deserialize
+/// without error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Role {
+    /// Full administrative privileges.
+    Admin,
+
+    /// Can moderate content but cannot manage other users.
+    Moderator,
+
+    /// An ordinary end user.
+    #[default]
+    Normal,
+
+    /// A non-interactive, service-to-service account.
+    Service,
+}
+
+/// An action gated by [`authorize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Create, edit, or delete other users' profiles.
+    ManageUsers,
+
+    /// Moderate user-submitted content.
+    ModerateContent,
+
+    /// Read or update one's own profile.
+    ReadOwnProfile,
+
+    /// Call service-to-service APIs.
+    UseService,
+}
+
+/// Returns whether `role` is permitted to perform `action`.
+///
+/// `Role::Admin` is permitted to perform every action.
+pub fn authorize(role: Role, action: Action) -> bool {
+    matches!(
+        (role, action),
+        (Role::Admin, _)
+            | (Role::Moderator, Action::ModerateContent | Action::ReadOwnProfile)
+            | (Role::Normal, Action::ReadOwnProfile)
+            | (Role::Service, Action::UseService)
+    )
 }
 
 /// Represents optional personal attributes of a user, such as name and custom fields.
@@ -88,6 +169,12 @@ pub struct UserAttributes {
     pub extra: Map<String, Value>,
 }
 
+impl Default for UserAttributes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl UserAttributes {
     /// Creates a new, empty set of user attributes.
     pub fn new() -> Self {
@@ -136,6 +223,11 @@ pub struct UserPreferences {
     /// Whether the user has opted in to receive newsletters.
     pub newsletter_opt_in: bool,
 
+    /// Whether the user's email address has completed verification
+    /// (double opt-in) via the `verification` module.
+    #[serde(default)]
+    pub email_verified: bool,
+
     /// Preferred language (e.g., "en", "id").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
@@ -154,6 +246,7 @@ impl UserPreferences {
     pub fn new() -> Self {
         Self {
             newsletter_opt_in: false,
+            email_verified: false,
             language: None,
             currency: None,
             extra: Default::default(),
@@ -170,6 +263,16 @@ impl UserPreferences {
         self.newsletter_opt_in
     }
 
+    /// Sets the email-verified flag.
+    pub fn set_email_verified(&mut self, verified: bool) {
+        self.email_verified = verified;
+    }
+
+    /// Returns whether the user's email address has been verified.
+    pub fn get_email_verified(&self) -> bool {
+        self.email_verified
+    }
+
     /// Sets the preferred language.
     pub fn set_language(&mut self, language: String) {
         self.language = Some(language);
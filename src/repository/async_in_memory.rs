@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::error::ProfileKitError;
+use crate::model::UserProfile;
+use crate::repository::async_traits::AsyncUserProfileRepository;
+
+/// An in-memory, `tokio::sync::RwLock`-backed implementation of
+/// [`AsyncUserProfileRepository`], intended for use in Tokio services and
+/// tests that want an async-native store without standing up a real
+/// database.
+pub struct AsyncInMemoryUserProfileRepository {
+    storage: Arc<RwLock<HashMap<String, UserProfile>>>,
+}
+
+impl AsyncInMemoryUserProfileRepository {
+    /// Creates a new, empty repository.
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for AsyncInMemoryUserProfileRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AsyncUserProfileRepository for AsyncInMemoryUserProfileRepository {
+    async fn get_by_id(&self, id: String) -> Result<Option<UserProfile>, ProfileKitError> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(&id).cloned())
+    }
+
+    async fn create(&self, profile: UserProfile) -> Result<(), ProfileKitError> {
+        let mut storage = self.storage.write().await;
+        if storage.contains_key(&profile.id) {
+            return Err(ProfileKitError::AlreadyExists);
+        }
+        storage.insert(profile.id.clone(), profile);
+        Ok(())
+    }
+
+    async fn update(&self, profile: UserProfile) -> Result<(), ProfileKitError> {
+        let mut storage = self.storage.write().await;
+        if !storage.contains_key(&profile.id) {
+            return Err(ProfileKitError::NotFound);
+        }
+        storage.insert(profile.id.clone(), profile);
+        Ok(())
+    }
+
+    async fn delete(&self, id: String) -> Result<(), ProfileKitError> {
+        let mut storage = self.storage.write().await;
+        if storage.remove(&id).is_none() {
+            return Err(ProfileKitError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_profile() -> UserProfile {
+        UserProfile::new("u1".to_string(), "test@example.com".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_create_and_get() {
+        let repo = AsyncInMemoryUserProfileRepository::new();
+        let profile = sample_profile();
+        repo.create(profile.clone()).await.unwrap();
+        let fetched = repo.get_by_id("u1".to_string()).await.unwrap();
+        assert_eq!(fetched, Some(profile));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_create() {
+        let repo = AsyncInMemoryUserProfileRepository::new();
+        let profile = sample_profile();
+        repo.create(profile.clone()).await.unwrap();
+        let result = repo.create(profile).await;
+        assert!(matches!(result, Err(ProfileKitError::AlreadyExists)));
+    }
+
+    #[tokio::test]
+    async fn test_update_nonexistent() {
+        let repo = AsyncInMemoryUserProfileRepository::new();
+        let profile = sample_profile();
+        let result = repo.update(profile).await;
+        assert!(matches!(result, Err(ProfileKitError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let repo = AsyncInMemoryUserProfileRepository::new();
+        let profile = sample_profile();
+        repo.create(profile.clone()).await.unwrap();
+        repo.delete("u1".to_string()).await.unwrap();
+        let fetched = repo.get_by_id("u1".to_string()).await.unwrap();
+        assert!(fetched.is_none());
+    }
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+
+use crate::error::ProfileKitError;
+use crate::model::UserProfile;
+
+/// Async abstraction for user profile data access and persistence.
+///
+/// Mirrors the CRUD methods of
+/// [`UserProfileRepository`](crate::repository::UserProfileRepository)
+/// (`get_by_id`, `create`, `update`, `delete`) for backends that are naturally non-blocking (e.g. Postgres via
+/// `tokio-postgres`, gRPC, HTTP), so implementors can await I/O instead of
+/// blocking the executor. The confirmation, listing and search methods of
+/// the sync trait have no async counterpart here.
+#[async_trait]
+pub trait AsyncUserProfileRepository {
+    /// Retrieves a user profile by its unique ID.
+    ///
+    /// Returns `Ok(Some(UserProfile))` if found,
+    /// `Ok(None)` if not found,
+    /// or `Err(ProfileKitError)` if an error occurred during the operation.
+    async fn get_by_id(&self, id: String) -> Result<Option<UserProfile>, ProfileKitError>;
+
+    /// Creates a new user profile in the storage backend.
+    ///
+    /// Returns an error if the profile already exists, or if storage fails.
+    async fn create(&self, profile: UserProfile) -> Result<(), ProfileKitError>;
+
+    /// Updates an existing user profile.
+    ///
+    /// Returns an error if the profile does not exist, or if storage fails.
+    async fn update(&self, profile: UserProfile) -> Result<(), ProfileKitError>;
+
+    /// Deletes a user profile by its ID.
+    ///
+    /// Returns an error if deletion fails.
+    async fn delete(&self, id: String) -> Result<(), ProfileKitError>;
+}
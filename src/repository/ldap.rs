@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use ldap3::{LdapConn, Scope, SearchEntry};
+
+use crate::error::ProfileKitError;
+use crate::model::{Role, UserAttributes, UserProfile};
+use crate::repository::traits::UserProfileRepository;
+use crate::verification::VerificationToken;
+
+/// Configuration for binding to and searching an LDAP directory.
+pub struct LdapConfig {
+    /// The LDAP server URL, e.g. `ldap://directory.example.com:389`.
+    pub url: String,
+
+    /// The base DN to search under, e.g. `ou=people,dc=example,dc=com`.
+    pub base_dn: String,
+
+    /// A search filter with a `{uid}` placeholder substituted with the ID
+    /// being looked up, e.g. `(uid={uid})`.
+    pub search_filter_template: String,
+
+    /// An optional service-account DN to bind as before searching.
+    /// Omit for directories that allow anonymous search.
+    pub bind_dn: Option<String>,
+
+    /// Password for `bind_dn`.
+    pub bind_password: Option<String>,
+}
+
+/// Read-only [`UserProfileRepository`] backed by an LDAP directory.
+///
+/// Maps directory attributes onto [`UserProfile`]: `uid` to `id`, `mail`
+/// to `email`, `givenName`/`sn` to first/last name, and any remaining
+/// attributes into [`UserAttributes::extra`].
+///
+/// Write methods (`create`, `update`, `delete`, ...) return
+/// `ProfileKitError::InvalidInput` since directory entries are normally
+/// managed out-of-band (e.g. by an identity admin tool), not through this
+/// crate.
+pub struct LdapUserProfileRepository {
+    config: LdapConfig,
+}
+
+impl LdapUserProfileRepository {
+    /// Creates a repository bound to the given directory configuration.
+    pub fn new(config: LdapConfig) -> Self {
+        Self { config }
+    }
+
+    fn connect(&self) -> Result<LdapConn, ProfileKitError> {
+        let mut conn =
+            LdapConn::new(&self.config.url).map_err(|e| ProfileKitError::Database(e.to_string()))?;
+        if let (Some(dn), Some(password)) = (&self.config.bind_dn, &self.config.bind_password) {
+            conn.simple_bind(dn, password)
+                .and_then(|res| res.success())
+                .map_err(|e| ProfileKitError::Database(e.to_string()))?;
+        }
+        Ok(conn)
+    }
+
+    fn search_one(
+        &self,
+        conn: &mut LdapConn,
+        filter: &str,
+    ) -> Result<Option<UserProfile>, ProfileKitError> {
+        let (entries, _) = conn
+            .search(
+                &self.config.base_dn,
+                Scope::Subtree,
+                filter,
+                vec!["*"],
+            )
+            .and_then(|res| res.success())
+            .map_err(|e| ProfileKitError::Database(e.to_string()))?;
+
+        Ok(entries
+            .into_iter()
+            .next()
+            .map(|entry| Self::entry_to_profile(SearchEntry::construct(entry))))
+    }
+
+    fn entry_to_profile(entry: SearchEntry) -> UserProfile {
+        let mut attrs = entry.attrs;
+        let id = take_first(&mut attrs, "uid").unwrap_or_default();
+        let email = take_first(&mut attrs, "mail").unwrap_or_default();
+        let first_name = take_first(&mut attrs, "givenName");
+        let last_name = take_first(&mut attrs, "sn");
+
+        let mut user_attrs = UserAttributes::new();
+        if let Some(first_name) = first_name {
+            user_attrs.set_first_name(first_name);
+        }
+        if let Some(last_name) = last_name {
+            user_attrs.set_last_name(last_name);
+        }
+        for (key, mut values) in attrs {
+            if !values.is_empty() {
+                user_attrs.set_extra(key, serde_json::Value::String(values.remove(0)));
+            }
+        }
+
+        let mut profile = UserProfile::new(id, email);
+        profile.set_attributes(Some(user_attrs));
+        profile
+    }
+
+    /// Resolves `id`'s directory DN by searching, then binds as that DN
+    /// with `password` to verify the credential.
+    ///
+    /// Returns `Ok(false)` for an unknown ID, an empty `password`, or a
+    /// failed bind, and `Err(ProfileKitError::Database)` for a connection
+    /// or search failure.
+    ///
+    /// An empty password is rejected up front because RFC 4513 treats a
+    /// simple bind with a non-empty DN and an empty password as an
+    /// *unauthenticated* bind, which many directories accept without
+    /// checking any credential at all.
+    pub fn authenticate(&self, id: &str, password: &str) -> Result<bool, ProfileKitError> {
+        if password.is_empty() {
+            return Ok(false);
+        }
+
+        let mut conn = self.connect()?;
+        let filter = self
+            .config
+            .search_filter_template
+            .replace("{uid}", &ldap_escape(id));
+
+        let (entries, _) = conn
+            .search(&self.config.base_dn, Scope::Subtree, &filter, vec!["dn"])
+            .and_then(|res| res.success())
+            .map_err(|e| ProfileKitError::Database(e.to_string()))?;
+
+        let Some(entry) = entries.into_iter().next() else {
+            return Ok(false);
+        };
+        let dn = SearchEntry::construct(entry).dn;
+
+        let mut user_conn = LdapConn::new(&self.config.url)
+            .map_err(|e| ProfileKitError::Database(e.to_string()))?;
+        Ok(user_conn.simple_bind(&dn, password).and_then(|res| res.success()).is_ok())
+    }
+}
+
+fn take_first(attrs: &mut HashMap<String, Vec<String>>, key: &str) -> Option<String> {
+    attrs
+        .remove(key)
+        .and_then(|mut values| (!values.is_empty()).then(|| values.remove(0)))
+}
+
+/// Escapes characters with special meaning in an LDAP search filter
+/// (RFC 4515), so values coming from user input cannot inject filter
+/// clauses.
+fn ldap_escape(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '*' => escaped.push_str("\\2a"),
+            '(' => escaped.push_str("\\28"),
+            ')' => escaped.push_str("\\29"),
+            '\\' => escaped.push_str("\\5c"),
+            '\0' => escaped.push_str("\\00"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const READ_ONLY: &str = "LdapUserProfileRepository is read-only";
+
+impl UserProfileRepository for LdapUserProfileRepository {
+    fn get_by_id(&self, id: String) -> Result<Option<UserProfile>, ProfileKitError> {
+        let mut conn = self.connect()?;
+        let filter = self
+            .config
+            .search_filter_template
+            .replace("{uid}", &ldap_escape(&id));
+        self.search_one(&mut conn, &filter)
+    }
+
+    fn create(&self, _profile: UserProfile) -> Result<(), ProfileKitError> {
+        Err(ProfileKitError::InvalidInput(READ_ONLY.to_string()))
+    }
+
+    fn update(&self, _profile: UserProfile) -> Result<(), ProfileKitError> {
+        Err(ProfileKitError::InvalidInput(READ_ONLY.to_string()))
+    }
+
+    fn delete(&self, _id: String) -> Result<(), ProfileKitError> {
+        Err(ProfileKitError::InvalidInput(READ_ONLY.to_string()))
+    }
+
+    fn enqueue_confirmation(
+        &self,
+        _profile: &UserProfile,
+        _token: VerificationToken,
+    ) -> Result<(), ProfileKitError> {
+        Err(ProfileKitError::InvalidInput(READ_ONLY.to_string()))
+    }
+
+    fn confirm(&self, _token: &str, _now: i64) -> Result<(), ProfileKitError> {
+        Err(ProfileKitError::InvalidInput(READ_ONLY.to_string()))
+    }
+
+    fn list(&self, _page: usize, _per_page: usize) -> Result<Vec<UserProfile>, ProfileKitError> {
+        Err(ProfileKitError::InvalidInput(
+            "LdapUserProfileRepository does not support listing".to_string(),
+        ))
+    }
+
+    fn count(&self) -> Result<usize, ProfileKitError> {
+        Err(ProfileKitError::InvalidInput(
+            "LdapUserProfileRepository does not support counting".to_string(),
+        ))
+    }
+
+    fn find_by_email(&self, email: String) -> Result<Option<UserProfile>, ProfileKitError> {
+        let mut conn = self.connect()?;
+        let filter = format!("(mail={})", ldap_escape(&email));
+        self.search_one(&mut conn, &filter)
+    }
+
+    fn find_by_role(&self, _role: Role) -> Result<Vec<UserProfile>, ProfileKitError> {
+        Err(ProfileKitError::InvalidInput(
+            "LdapUserProfileRepository does not track roles".to_string(),
+        ))
+    }
+}
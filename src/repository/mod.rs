@@ -6,4 +6,37 @@
 /// Implementors of this trait may provide in-memory, database,
 /// remote API (e.g., gRPC), or mock implementations.
 pub mod traits;
-pub use traits::UserProfileRepository;
\ No newline at end of file
+pub use traits::UserProfileRepository;
+
+/// Async counterpart of [`UserProfileRepository`] for non-blocking backends
+/// (Postgres, gRPC, HTTP, ...).
+///
+/// Enabled by the `async` cargo feature.
+#[cfg(feature = "async")]
+pub mod async_traits;
+#[cfg(feature = "async")]
+pub use async_traits::AsyncUserProfileRepository;
+
+/// Tokio-backed in-memory implementation of [`AsyncUserProfileRepository`].
+///
+/// Enabled by the `async` cargo feature.
+#[cfg(feature = "async")]
+pub mod async_in_memory;
+#[cfg(feature = "async")]
+pub use async_in_memory::AsyncInMemoryUserProfileRepository;
+
+/// `tokio-postgres`-backed implementation of [`AsyncUserProfileRepository`].
+///
+/// Enabled by the `postgres` cargo feature.
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "postgres")]
+pub use postgres::PgUserProfileRepository;
+
+/// Read-only, LDAP-backed implementation of [`UserProfileRepository`].
+///
+/// Enabled by the `ldap` cargo feature.
+#[cfg(feature = "ldap")]
+pub mod ldap;
+#[cfg(feature = "ldap")]
+pub use ldap::{LdapConfig, LdapUserProfileRepository};
\ No newline at end of file
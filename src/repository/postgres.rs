@@ -0,0 +1,209 @@
+use tokio_postgres::types::Json;
+use tokio_postgres::Client;
+
+use crate::error::ProfileKitError;
+use crate::model::{Role, UserProfile};
+use crate::repository::async_traits::AsyncUserProfileRepository;
+
+use async_trait::async_trait;
+
+/// `tokio-postgres`-backed implementation of [`AsyncUserProfileRepository`].
+///
+/// Stores `attributes` and `preferences` as `jsonb` columns; the `extra`
+/// flatten maps travel inline as part of those JSON blobs since they are
+/// already folded into the serialized structs by `serde`.
+pub struct PgUserProfileRepository {
+    client: Client,
+}
+
+impl PgUserProfileRepository {
+    /// Wraps an existing, already-connected `tokio_postgres::Client`.
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Creates the `user_profiles` table if it does not already exist.
+    ///
+    /// Safe to call on every startup.
+    pub async fn ensure_schema(&self) -> Result<(), ProfileKitError> {
+        self.client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS user_profiles (
+                    id TEXT PRIMARY KEY,
+                    email TEXT NOT NULL,
+                    attributes JSONB,
+                    preferences JSONB,
+                    role TEXT NOT NULL DEFAULT 'Normal'
+                )",
+            )
+            .await
+            .map_err(|e| ProfileKitError::Database(e.to_string()))
+    }
+
+    fn row_to_profile(row: &tokio_postgres::Row) -> UserProfile {
+        let attributes: Option<Json<serde_json::Value>> = row.get("attributes");
+        let preferences: Option<Json<serde_json::Value>> = row.get("preferences");
+        let role: String = row.get("role");
+
+        UserProfile {
+            id: row.get("id"),
+            email: row.get("email"),
+            attributes: attributes.and_then(|Json(value)| serde_json::from_value(value).ok()),
+            preferences: preferences.and_then(|Json(value)| serde_json::from_value(value).ok()),
+            #[cfg(feature = "credentials")]
+            credential: None,
+            role: role_from_text(&role),
+        }
+    }
+}
+
+fn role_to_text(role: Role) -> String {
+    match serde_json::to_value(role) {
+        Ok(serde_json::Value::String(text)) => text,
+        _ => "Normal".to_string(),
+    }
+}
+
+fn role_from_text(text: &str) -> Role {
+    serde_json::from_value(serde_json::Value::String(text.to_string())).unwrap_or_default()
+}
+
+#[async_trait]
+impl AsyncUserProfileRepository for PgUserProfileRepository {
+    async fn get_by_id(&self, id: String) -> Result<Option<UserProfile>, ProfileKitError> {
+        let row = self
+            .client
+            .query_opt(
+                "SELECT id, email, attributes, preferences, role FROM user_profiles WHERE id = $1",
+                &[&id],
+            )
+            .await
+            .map_err(|e| ProfileKitError::Database(e.to_string()))?;
+
+        Ok(row.as_ref().map(Self::row_to_profile))
+    }
+
+    async fn create(&self, profile: UserProfile) -> Result<(), ProfileKitError> {
+        let attributes = Json(&profile.attributes);
+        let preferences = Json(&profile.preferences);
+        let role = role_to_text(profile.role);
+
+        let rows = self
+            .client
+            .execute(
+                "INSERT INTO user_profiles (id, email, attributes, preferences, role)
+                 VALUES ($1, $2, $3, $4, $5)
+                 ON CONFLICT (id) DO NOTHING",
+                &[&profile.id, &profile.email, &attributes, &preferences, &role],
+            )
+            .await
+            .map_err(|e| ProfileKitError::Database(e.to_string()))?;
+
+        if rows == 0 {
+            return Err(ProfileKitError::AlreadyExists);
+        }
+        Ok(())
+    }
+
+    async fn update(&self, profile: UserProfile) -> Result<(), ProfileKitError> {
+        let attributes = Json(&profile.attributes);
+        let preferences = Json(&profile.preferences);
+        let role = role_to_text(profile.role);
+
+        let rows = self
+            .client
+            .execute(
+                "UPDATE user_profiles
+                     SET email = $2,
+                         attributes = $3,
+                         preferences = $4,
+                         role = $5
+                 WHERE id = $1",
+                &[&profile.id, &profile.email, &attributes, &preferences, &role],
+            )
+            .await
+            .map_err(|e| ProfileKitError::Database(e.to_string()))?;
+
+        if rows == 0 {
+            return Err(ProfileKitError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: String) -> Result<(), ProfileKitError> {
+        let rows = self
+            .client
+            .execute("DELETE FROM user_profiles WHERE id = $1", &[&id])
+            .await
+            .map_err(|e| ProfileKitError::Database(e.to_string()))?;
+
+        if rows == 0 {
+            return Err(ProfileKitError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! These tests talk to a real Postgres instance and only run when
+    //! `PROFILE_KIT_TEST_DATABASE_URL` is set, e.g.:
+    //!
+    //! ```text
+    //! PROFILE_KIT_TEST_DATABASE_URL=postgres://postgres@localhost/profile_kit_test cargo test --features postgres
+    //! ```
+    use super::*;
+    use tokio_postgres::NoTls;
+
+    async fn connect() -> Option<PgUserProfileRepository> {
+        let url = std::env::var("PROFILE_KIT_TEST_DATABASE_URL").ok()?;
+        let (client, connection) = tokio_postgres::connect(&url, NoTls).await.unwrap();
+        tokio::spawn(async move {
+            let _ = connection.await;
+        });
+        let repo = PgUserProfileRepository::new(client);
+        repo.ensure_schema().await.unwrap();
+        Some(repo)
+    }
+
+    #[tokio::test]
+    async fn test_create_get_update_delete() {
+        let Some(repo) = connect().await else {
+            eprintln!("skipping: PROFILE_KIT_TEST_DATABASE_URL not set");
+            return;
+        };
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut profile = UserProfile::new(id.clone(), "pg-test@example.com".to_string());
+        profile.set_role(Role::Admin);
+
+        repo.create(profile.clone()).await.unwrap();
+        let fetched = repo.get_by_id(id.clone()).await.unwrap();
+        assert_eq!(fetched, Some(profile.clone()));
+        assert_eq!(fetched.unwrap().get_role(), Role::Admin);
+
+        let result = repo.create(profile.clone()).await;
+        assert!(matches!(result, Err(ProfileKitError::AlreadyExists)));
+
+        let mut updated = profile.clone();
+        updated.set_email("pg-test-updated@example.com".to_string());
+        repo.update(updated.clone()).await.unwrap();
+        assert_eq!(repo.get_by_id(id.clone()).await.unwrap(), Some(updated));
+
+        repo.delete(id.clone()).await.unwrap();
+        assert_eq!(repo.get_by_id(id).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_update_nonexistent() {
+        let Some(repo) = connect().await else {
+            eprintln!("skipping: PROFILE_KIT_TEST_DATABASE_URL not set");
+            return;
+        };
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let profile = UserProfile::new(id, "pg-nonexistent@example.com".to_string());
+        let result = repo.update(profile).await;
+        assert!(matches!(result, Err(ProfileKitError::NotFound)));
+    }
+}
@@ -1,5 +1,6 @@
-use crate::model::UserProfile;
+use crate::model::{Role, UserProfile};
 use crate::error::ProfileKitError;
+use crate::verification::VerificationToken;
 
 /// Abstraction for user profile data access and persistence.
 ///
@@ -30,4 +31,44 @@ pub trait UserProfileRepository {
     ///
     /// Returns an error if deletion fails.
     fn delete(&self, id: String) -> Result<(), ProfileKitError>;
+
+    /// Queues a pending confirmation for `token`, associated with `profile`.
+    ///
+    /// Implementors typically append to an outbox-style store so a
+    /// separate process can deliver the confirmation (email, SMS, ...)
+    /// and later settle it via [`confirm`](Self::confirm).
+    fn enqueue_confirmation(
+        &self,
+        profile: &UserProfile,
+        token: VerificationToken,
+    ) -> Result<(), ProfileKitError>;
+
+    /// Confirms a previously enqueued token.
+    ///
+    /// On success, flips `newsletter_opt_in` (for
+    /// `Purpose::NewsletterConfirmation`) or `email_verified` (for
+    /// `Purpose::EmailConfirmation`) on the associated profile's
+    /// preferences, and removes the token from the outbox.
+    ///
+    /// Returns `Err(ProfileKitError::InvalidInput)` if no matching,
+    /// unexpired token is queued.
+    fn confirm(&self, token: &str, now: i64) -> Result<(), ProfileKitError>;
+
+    /// Returns a 0-indexed page of profiles, `per_page` at a time.
+    ///
+    /// Implementors should return results in a stable order so repeated
+    /// calls paginate consistently.
+    fn list(&self, page: usize, per_page: usize) -> Result<Vec<UserProfile>, ProfileKitError>;
+
+    /// Returns the total number of stored profiles.
+    fn count(&self) -> Result<usize, ProfileKitError>;
+
+    /// Looks up a profile by email address (case-insensitive, matching
+    /// the lowercasing `UserProfile::new` applies).
+    ///
+    /// Returns `Ok(None)` if no profile has that email.
+    fn find_by_email(&self, email: String) -> Result<Option<UserProfile>, ProfileKitError>;
+
+    /// Returns every profile with the given `role`.
+    fn find_by_role(&self, role: Role) -> Result<Vec<UserProfile>, ProfileKitError>;
 }
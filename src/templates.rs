@@ -0,0 +1,204 @@
+use std::path::Path;
+
+use handlebars::Handlebars;
+use serde_json::json;
+
+use crate::error::ProfileKitError;
+use crate::model::UserProfile;
+
+const EMAIL_CONFIRMATION: &str = "email_confirmation";
+const WELCOME: &str = "welcome";
+const NEWSLETTER_CONFIRMATION: &str = "newsletter_confirmation";
+
+const DEFAULT_TEMPLATES: &[(&str, &str)] = &[
+    (
+        EMAIL_CONFIRMATION,
+        "Hi {{display_name}}, please confirm your email address using code {{confirmation_token}}.",
+    ),
+    (WELCOME, "Welcome, {{display_name}}! We're glad you're here."),
+    (
+        NEWSLETTER_CONFIRMATION,
+        "Hi {{display_name}}, please confirm your newsletter subscription using code {{confirmation_token}}.",
+    ),
+];
+
+/// A profile lifecycle event to render a notification message for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileEvent {
+    /// Ask the user to confirm ownership of their email address.
+    EmailConfirmation {
+        /// The token the user must present to confirm.
+        confirmation_token: String,
+    },
+
+    /// Welcome a newly created user.
+    Welcome,
+
+    /// Ask the user to confirm a newsletter double opt-in.
+    NewsletterConfirmation {
+        /// The token the user must present to confirm.
+        confirmation_token: String,
+    },
+}
+
+impl ProfileEvent {
+    fn template_name(&self) -> &'static str {
+        match self {
+            ProfileEvent::EmailConfirmation { .. } => EMAIL_CONFIRMATION,
+            ProfileEvent::Welcome => WELCOME,
+            ProfileEvent::NewsletterConfirmation { .. } => NEWSLETTER_CONFIRMATION,
+        }
+    }
+
+    fn confirmation_token(&self) -> Option<&str> {
+        match self {
+            ProfileEvent::EmailConfirmation { confirmation_token }
+            | ProfileEvent::NewsletterConfirmation { confirmation_token } => {
+                Some(confirmation_token)
+            }
+            ProfileEvent::Welcome => None,
+        }
+    }
+}
+
+/// Renders [`ProfileEvent`] notification messages with Handlebars,
+/// falling back to an embedded default template set unless a caller has
+/// registered an override.
+pub struct TemplateRegistry {
+    handlebars: Handlebars<'static>,
+}
+
+impl TemplateRegistry {
+    /// Creates a registry pre-loaded with the default template set.
+    pub fn new() -> Self {
+        let mut handlebars = Handlebars::new();
+        for (name, template) in DEFAULT_TEMPLATES {
+            handlebars
+                .register_template_string(name, template)
+                .expect("embedded default templates must be valid handlebars");
+        }
+        Self { handlebars }
+    }
+
+    /// Overrides the template used to render `event`s of this kind with
+    /// `template` (Handlebars syntax).
+    pub fn register_str(
+        &mut self,
+        event: &ProfileEvent,
+        template: &str,
+    ) -> Result<(), ProfileKitError> {
+        self.handlebars
+            .register_template_string(event.template_name(), template)
+            .map_err(|e| ProfileKitError::InvalidInput(e.to_string()))
+    }
+
+    /// Overrides templates from files in `dir`, one per event named
+    /// `<event>.hbs` (e.g. `welcome.hbs`). Events with no matching file
+    /// keep their current template.
+    pub fn load_dir(&mut self, dir: &Path) -> Result<(), ProfileKitError> {
+        for (name, _) in DEFAULT_TEMPLATES {
+            let path = dir.join(format!("{name}.hbs"));
+            if !path.exists() {
+                continue;
+            }
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|e| ProfileKitError::InvalidInput(e.to_string()))?;
+            self.handlebars
+                .register_template_string(name, contents)
+                .map_err(|e| ProfileKitError::InvalidInput(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Renders `event` for `profile`, using `profile`'s first name (or its
+    /// email, if no first name is set) as the `display_name` variable.
+    pub fn render_event(
+        &self,
+        event: ProfileEvent,
+        profile: &UserProfile,
+    ) -> Result<String, ProfileKitError> {
+        let display_name = profile
+            .get_attributes()
+            .and_then(|attrs| attrs.get_first_name())
+            .cloned()
+            .unwrap_or_else(|| profile.get_email().to_string());
+
+        let data = json!({
+            "display_name": display_name,
+            "email": profile.get_email(),
+            "confirmation_token": event.confirmation_token(),
+        });
+
+        self.handlebars
+            .render(event.template_name(), &data)
+            .map_err(|e| ProfileKitError::InvalidInput(e.to_string()))
+    }
+}
+
+impl Default for TemplateRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::UserAttributes;
+
+    fn sample_profile() -> UserProfile {
+        let mut attrs = UserAttributes::new();
+        attrs.set_first_name("Jane".to_string());
+        let mut profile = UserProfile::new("u1".to_string(), "jane@example.com".to_string());
+        profile.set_attributes(Some(attrs));
+        profile
+    }
+
+    #[test]
+    fn test_render_default_welcome() {
+        let registry = TemplateRegistry::new();
+        let rendered = registry
+            .render_event(ProfileEvent::Welcome, &sample_profile())
+            .unwrap();
+        assert_eq!(rendered, "Welcome, Jane! We're glad you're here.");
+    }
+
+    #[test]
+    fn test_render_default_email_confirmation() {
+        let registry = TemplateRegistry::new();
+        let rendered = registry
+            .render_event(
+                ProfileEvent::EmailConfirmation {
+                    confirmation_token: "abc123".to_string(),
+                },
+                &sample_profile(),
+            )
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "Hi Jane, please confirm your email address using code abc123."
+        );
+    }
+
+    #[test]
+    fn test_render_falls_back_to_email_without_first_name() {
+        let registry = TemplateRegistry::new();
+        let profile = UserProfile::new("u2".to_string(), "noname@example.com".to_string());
+        let rendered = registry
+            .render_event(ProfileEvent::Welcome, &profile)
+            .unwrap();
+        assert_eq!(rendered, "Welcome, noname@example.com! We're glad you're here.");
+    }
+
+    #[test]
+    fn test_register_str_overrides_default() {
+        let mut registry = TemplateRegistry::new();
+        registry
+            .register_str(&ProfileEvent::Welcome, "Hey {{display_name}}!")
+            .unwrap();
+        let rendered = registry
+            .render_event(ProfileEvent::Welcome, &sample_profile())
+            .unwrap();
+        assert_eq!(rendered, "Hey Jane!");
+    }
+}
@@ -0,0 +1,131 @@
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ProfileKitError;
+
+const BASE62_ALPHABET: &[u8] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// What a [`VerificationToken`] is confirming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Purpose {
+    /// Confirms ownership of the account's email address.
+    EmailConfirmation,
+
+    /// Confirms a double opt-in for the newsletter.
+    NewsletterConfirmation,
+}
+
+/// A single-use, time-limited token proving that a user confirmed an
+/// action (email ownership, newsletter opt-in) sent to their email address.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationToken {
+    /// The random, base62-encoded token value shared with the user.
+    pub token: String,
+
+    /// The ID of the user this token was issued for.
+    pub user_id: String,
+
+    /// What confirming this token accomplishes.
+    pub purpose: Purpose,
+
+    /// Unix timestamp (seconds) after which the token is no longer valid.
+    pub expires_at: i64,
+}
+
+/// Generates [`VerificationToken`]s with a configurable time-to-live.
+pub struct TokenGenerator {
+    ttl_seconds: i64,
+}
+
+impl TokenGenerator {
+    /// Creates a generator that issues tokens valid for `ttl_seconds`
+    /// seconds from the time they are generated.
+    pub fn new(ttl_seconds: i64) -> Self {
+        Self { ttl_seconds }
+    }
+
+    /// Generates a new token for `user_id` and `purpose`, expiring
+    /// `ttl_seconds` after `now` (a Unix timestamp in seconds).
+    pub fn generate(&self, user_id: String, purpose: Purpose, now: i64) -> VerificationToken {
+        VerificationToken {
+            token: generate_base62_token(),
+            user_id,
+            purpose,
+            expires_at: now + self.ttl_seconds,
+        }
+    }
+}
+
+const TOKEN_LEN: usize = 32;
+
+/// Generates a 32-character, uniformly random base62 token.
+///
+/// Draws random bytes one at a time and rejects any byte that would bias
+/// the modulo-62 mapping (i.e. anything at or above the largest multiple
+/// of 62 that fits in a `u8`), so every output character is equally
+/// likely.
+fn generate_base62_token() -> String {
+    let alphabet_len = BASE62_ALPHABET.len() as u16;
+    let reject_at = (256 - 256 % alphabet_len) as u8;
+
+    let mut rng = rand::thread_rng();
+    let mut token = String::with_capacity(TOKEN_LEN);
+    let mut byte = [0u8; 1];
+    while token.len() < TOKEN_LEN {
+        rng.fill_bytes(&mut byte);
+        if byte[0] < reject_at {
+            token.push(BASE62_ALPHABET[(byte[0] as usize) % BASE62_ALPHABET.len()] as char);
+        }
+    }
+    token
+}
+
+/// Verifies that `candidate` matches `token` and that `token` has not
+/// expired as of `now` (a Unix timestamp in seconds).
+///
+/// Returns `Err(ProfileKitError::InvalidInput)` if the token does not
+/// match or has expired.
+pub fn verify(token: &VerificationToken, candidate: &str, now: i64) -> Result<(), ProfileKitError> {
+    if token.token != candidate {
+        return Err(ProfileKitError::InvalidInput(
+            "verification token does not match".to_string(),
+        ));
+    }
+    if now > token.expires_at {
+        return Err(ProfileKitError::InvalidInput(
+            "verification token has expired".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_and_verify() {
+        let generator = TokenGenerator::new(3600);
+        let token = generator.generate("u1".to_string(), Purpose::EmailConfirmation, 1_000);
+        assert_eq!(token.token.len(), 32);
+        assert_eq!(token.expires_at, 4_600);
+        assert!(verify(&token, &token.token, 1_000).is_ok());
+    }
+
+    #[test]
+    fn test_verify_wrong_token() {
+        let generator = TokenGenerator::new(3600);
+        let token = generator.generate("u1".to_string(), Purpose::NewsletterConfirmation, 1_000);
+        let result = verify(&token, "not-the-token", 1_000);
+        assert!(matches!(result, Err(ProfileKitError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_verify_expired_token() {
+        let generator = TokenGenerator::new(10);
+        let token = generator.generate("u1".to_string(), Purpose::EmailConfirmation, 1_000);
+        let result = verify(&token, &token.token, 1_011);
+        assert!(matches!(result, Err(ProfileKitError::InvalidInput(_))));
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use serde_json::json;
-    use profile_kit::model::{UserAttributes, UserPreferences, UserProfile};
+    use profile_kit::model::{authorize, Action, Role, UserAttributes, UserPreferences, UserProfile};
 
     #[test]
     fn test_user_profile_basic_setters_getters() {
@@ -81,4 +81,54 @@ mod tests {
 
         assert_eq!(profile, deserialized);
     }
+
+    #[test]
+    fn test_role_defaults_to_normal() {
+        let profile = UserProfile::new("1".into(), "role@example.com".into());
+        assert_eq!(profile.get_role(), Role::Normal);
+    }
+
+    #[test]
+    fn test_role_set_get() {
+        let mut profile = UserProfile::new("1".into(), "role@example.com".into());
+        profile.set_role(Role::Admin);
+        assert_eq!(profile.get_role(), Role::Admin);
+    }
+
+    #[test]
+    fn test_role_serde_round_trip() {
+        let mut profile = UserProfile::new("1".into(), "role@example.com".into());
+        profile.set_role(Role::Moderator);
+
+        let json_str = serde_json::to_string(&profile).unwrap();
+        let deserialized: UserProfile = serde_json::from_str(&json_str).unwrap();
+
+        assert_eq!(deserialized.get_role(), Role::Moderator);
+    }
+
+    #[test]
+    fn test_role_missing_from_json_defaults_to_normal() {
+        // Simulates a profile persisted before `role` existed.
+        let json_str = r#"{"id":"1","email":"role@example.com"}"#;
+        let deserialized: UserProfile = serde_json::from_str(json_str).unwrap();
+        assert_eq!(deserialized.get_role(), Role::Normal);
+    }
+
+    #[test]
+    fn test_authorize_admin_can_do_anything() {
+        assert!(authorize(Role::Admin, Action::ManageUsers));
+        assert!(authorize(Role::Admin, Action::ModerateContent));
+    }
+
+    #[test]
+    fn test_authorize_normal_is_restricted_to_own_profile() {
+        assert!(authorize(Role::Normal, Action::ReadOwnProfile));
+        assert!(!authorize(Role::Normal, Action::ManageUsers));
+    }
+
+    #[test]
+    fn test_authorize_service_is_restricted_to_use_service() {
+        assert!(authorize(Role::Service, Action::UseService));
+        assert!(!authorize(Role::Service, Action::ModerateContent));
+    }
 }
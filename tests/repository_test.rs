@@ -1,17 +1,26 @@
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use profile_kit::error::ProfileKitError;
-use profile_kit::model::UserProfile;
+use profile_kit::model::{Role, UserProfile};
 use profile_kit::repository::UserProfileRepository;
+use profile_kit::verification::{Purpose, VerificationToken};
 
 pub struct InMemoryUserProfileRepository {
     storage: Arc<RwLock<HashMap<String, UserProfile>>>,
+    outbox: Arc<RwLock<Vec<VerificationToken>>>,
+}
+
+impl Default for InMemoryUserProfileRepository {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl InMemoryUserProfileRepository {
     pub fn new() -> Self {
         InMemoryUserProfileRepository {
             storage: Arc::new(RwLock::new(HashMap::new())),
+            outbox: Arc::new(RwLock::new(Vec::new())),
         }
     }
 }
@@ -47,13 +56,70 @@ impl UserProfileRepository for InMemoryUserProfileRepository {
         }
         Ok(())
     }
+
+    fn enqueue_confirmation(
+        &self,
+        _profile: &UserProfile,
+        token: VerificationToken,
+    ) -> Result<(), ProfileKitError> {
+        let mut outbox = self.outbox.write().map_err(|_| ProfileKitError::StorageError)?;
+        outbox.push(token);
+        Ok(())
+    }
+
+    fn confirm(&self, token: &str, now: i64) -> Result<(), ProfileKitError> {
+        let mut outbox = self.outbox.write().map_err(|_| ProfileKitError::StorageError)?;
+        let position = outbox
+            .iter()
+            .position(|pending| pending.token == token && pending.expires_at >= now)
+            .ok_or_else(|| {
+                ProfileKitError::InvalidInput("no matching, unexpired confirmation token".to_string())
+            })?;
+        let pending = outbox.remove(position);
+
+        let mut storage = self.storage.write().map_err(|_| ProfileKitError::StorageError)?;
+        let profile = storage
+            .get_mut(&pending.user_id)
+            .ok_or(ProfileKitError::NotFound)?;
+        let preferences = profile.preferences.get_or_insert_with(Default::default);
+        match pending.purpose {
+            Purpose::EmailConfirmation => preferences.email_verified = true,
+            Purpose::NewsletterConfirmation => preferences.newsletter_opt_in = true,
+        }
+        Ok(())
+    }
+
+    fn list(&self, page: usize, per_page: usize) -> Result<Vec<UserProfile>, ProfileKitError> {
+        let storage = self.storage.read().map_err(|_| ProfileKitError::StorageError)?;
+        let mut profiles: Vec<UserProfile> = storage.values().cloned().collect();
+        profiles.sort_by(|a, b| a.id.cmp(&b.id));
+        let start = page.saturating_mul(per_page);
+        Ok(profiles.into_iter().skip(start).take(per_page).collect())
+    }
+
+    fn count(&self) -> Result<usize, ProfileKitError> {
+        let storage = self.storage.read().map_err(|_| ProfileKitError::StorageError)?;
+        Ok(storage.len())
+    }
+
+    fn find_by_email(&self, email: String) -> Result<Option<UserProfile>, ProfileKitError> {
+        let normalized = email.to_ascii_lowercase();
+        let storage = self.storage.read().map_err(|_| ProfileKitError::StorageError)?;
+        Ok(storage.values().find(|p| p.email == normalized).cloned())
+    }
+
+    fn find_by_role(&self, role: Role) -> Result<Vec<UserProfile>, ProfileKitError> {
+        let storage = self.storage.read().map_err(|_| ProfileKitError::StorageError)?;
+        Ok(storage.values().filter(|p| p.role == role).cloned().collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use profile_kit::error::ProfileKitError;
-    use profile_kit::model::{UserAttributes, UserProfile};
+    use profile_kit::model::{Role, UserAttributes, UserProfile};
     use profile_kit::repository::UserProfileRepository;
+    use profile_kit::verification::{Purpose, VerificationToken};
     use crate::InMemoryUserProfileRepository;
 
     fn sample_profile() -> UserProfile {
@@ -118,4 +184,118 @@ mod tests {
         let result = repo.delete("does_not_exist".to_string());
         assert!(matches!(result, Err(ProfileKitError::NotFound)));
     }
+
+    #[test]
+    fn test_enqueue_and_confirm_email() {
+        let repo = InMemoryUserProfileRepository::new();
+        let profile = sample_profile();
+        repo.create(profile.clone()).unwrap();
+
+        let token = VerificationToken {
+            token: "abc123".to_string(),
+            user_id: profile.id.clone(),
+            purpose: Purpose::EmailConfirmation,
+            expires_at: 100,
+        };
+        repo.enqueue_confirmation(&profile, token.clone()).unwrap();
+        repo.confirm(&token.token, 50).unwrap();
+
+        let fetched = repo.get_by_id(profile.id).unwrap().unwrap();
+        assert!(fetched.get_preferences().unwrap().get_email_verified());
+    }
+
+    #[test]
+    fn test_confirm_newsletter() {
+        let repo = InMemoryUserProfileRepository::new();
+        let profile = sample_profile();
+        repo.create(profile.clone()).unwrap();
+
+        let token = VerificationToken {
+            token: "xyz789".to_string(),
+            user_id: profile.id.clone(),
+            purpose: Purpose::NewsletterConfirmation,
+            expires_at: 100,
+        };
+        repo.enqueue_confirmation(&profile, token.clone()).unwrap();
+        repo.confirm(&token.token, 50).unwrap();
+
+        let fetched = repo.get_by_id(profile.id).unwrap().unwrap();
+        assert!(fetched.get_preferences().unwrap().get_newsletter_opt_in());
+    }
+
+    #[test]
+    fn test_confirm_expired_token() {
+        let repo = InMemoryUserProfileRepository::new();
+        let profile = sample_profile();
+        repo.create(profile.clone()).unwrap();
+
+        let token = VerificationToken {
+            token: "expired".to_string(),
+            user_id: profile.id.clone(),
+            purpose: Purpose::EmailConfirmation,
+            expires_at: 100,
+        };
+        repo.enqueue_confirmation(&profile, token.clone()).unwrap();
+        let result = repo.confirm(&token.token, 200);
+        assert!(matches!(result, Err(ProfileKitError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_confirm_unknown_token() {
+        let repo = InMemoryUserProfileRepository::new();
+        let result = repo.confirm("nonexistent", 0);
+        assert!(matches!(result, Err(ProfileKitError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_list_and_count() {
+        let repo = InMemoryUserProfileRepository::new();
+        repo.create(UserProfile::new("u1".to_string(), "u1@example.com".to_string()))
+            .unwrap();
+        repo.create(UserProfile::new("u2".to_string(), "u2@example.com".to_string()))
+            .unwrap();
+        repo.create(UserProfile::new("u3".to_string(), "u3@example.com".to_string()))
+            .unwrap();
+
+        assert_eq!(repo.count().unwrap(), 3);
+
+        let page0 = repo.list(0, 2).unwrap();
+        assert_eq!(page0.len(), 2);
+        assert_eq!(page0[0].id, "u1");
+        assert_eq!(page0[1].id, "u2");
+
+        let page1 = repo.list(1, 2).unwrap();
+        assert_eq!(page1.len(), 1);
+        assert_eq!(page1[0].id, "u3");
+    }
+
+    #[test]
+    fn test_find_by_email_normalizes_case() {
+        let repo = InMemoryUserProfileRepository::new();
+        repo.create(sample_profile()).unwrap();
+
+        let found = repo.find_by_email("TEST@EXAMPLE.COM".to_string()).unwrap();
+        assert_eq!(found.map(|p| p.id), Some("u1".to_string()));
+
+        let missing = repo.find_by_email("nope@example.com".to_string()).unwrap();
+        assert!(missing.is_none());
+    }
+
+    #[test]
+    fn test_find_by_role() {
+        let repo = InMemoryUserProfileRepository::new();
+        let mut admin = sample_profile();
+        admin.set_role(Role::Admin);
+        repo.create(admin).unwrap();
+        repo.create(UserProfile::new("u2".to_string(), "u2@example.com".to_string()))
+            .unwrap();
+
+        let admins = repo.find_by_role(Role::Admin).unwrap();
+        assert_eq!(admins.len(), 1);
+        assert_eq!(admins[0].id, "u1");
+
+        let normal = repo.find_by_role(Role::Normal).unwrap();
+        assert_eq!(normal.len(), 1);
+        assert_eq!(normal[0].id, "u2");
+    }
 }